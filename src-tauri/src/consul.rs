@@ -0,0 +1,175 @@
+// Cluster-wide discovery via a Consul agent's catalog + health HTTP API.
+// Results merge into the same discovered-services map the localhost
+// scanners populate, and the merged map is written to disk on every
+// successful scan so the tool has something to show on the next launch
+// before any live scan completes.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use tauri::{AppHandle, Manager};
+use tracing::{info, warn};
+
+use crate::{current_timestamp, GrpcResponse, GrpcToolState, HealthStatus, LocalhostService, ServiceMetadata};
+
+const GRPC_TAG: &str = "grpc";
+const DISCOVERED_SERVICES_FILE: &str = "discovered_services.json";
+
+#[derive(Debug, Clone)]
+pub(crate) struct ConsulConfig {
+    pub(crate) address: String,
+    pub(crate) token: Option<String>,
+}
+
+#[tauri::command]
+pub(crate) async fn configure_consul(
+    address: String,
+    token: Option<String>,
+    state: tauri::State<'_, GrpcToolState>,
+) -> Result<GrpcResponse, String> {
+    *state.consul_config.lock().await = Some(ConsulConfig { address: address.clone(), token });
+
+    info!("🗂️ Consul agent configured at {}", address);
+
+    Ok(GrpcResponse {
+        success: true,
+        message: format!("Consul agent configured at {}", address),
+        data: None,
+    })
+}
+
+#[tauri::command]
+pub(crate) async fn refresh_from_consul(
+    app_handle: AppHandle,
+    state: tauri::State<'_, GrpcToolState>,
+) -> Result<GrpcResponse, String> {
+    let config = state.consul_config.lock().await.clone();
+    let config = match config {
+        Some(config) => config,
+        None => {
+            return Ok(GrpcResponse {
+                success: false,
+                message: "Consul is not configured; call configure_consul first".to_string(),
+                data: None,
+            })
+        }
+    };
+
+    let consul_services = match fetch_consul_grpc_services(&config).await {
+        Ok(services) => services,
+        Err(e) => {
+            return Ok(GrpcResponse {
+                success: false,
+                message: format!("Consul query failed: {}", e),
+                data: None,
+            })
+        }
+    };
+
+    let mut services = state.localhost_services.lock().await;
+    for svc in &consul_services {
+        services.insert(format!("{}:{}", svc.host, svc.port), svc.clone());
+    }
+    let merged = services.clone();
+    drop(services);
+
+    if let Err(e) = persist_discovered_services(&app_handle, &merged).await {
+        warn!("Failed to persist discovered services: {}", e);
+    }
+
+    info!("🎯 Consul refresh complete: merged {} service(s)", consul_services.len());
+
+    Ok(GrpcResponse {
+        success: true,
+        message: format!("Merged {} gRPC service(s) from Consul", consul_services.len()),
+        data: Some(serde_json::json!({ "services": merged })),
+    })
+}
+
+async fn fetch_consul_grpc_services(config: &ConsulConfig) -> anyhow::Result<Vec<LocalhostService>> {
+    let client = reqwest::Client::new();
+
+    let mut catalog_req = client.get(format!("{}/v1/catalog/services", config.address));
+    if let Some(token) = &config.token {
+        catalog_req = catalog_req.header("X-Consul-Token", token);
+    }
+    let catalog: HashMap<String, Vec<String>> = catalog_req.send().await?.error_for_status()?.json().await?;
+
+    let mut result = Vec::new();
+
+    for (name, tags) in catalog {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(GRPC_TAG)) {
+            continue;
+        }
+
+        let mut health_req = client.get(format!("{}/v1/health/service/{}", config.address, name));
+        if let Some(token) = &config.token {
+            health_req = health_req.header("X-Consul-Token", token);
+        }
+
+        let entries: Vec<serde_json::Value> = match health_req.send().await {
+            Ok(resp) => resp.error_for_status()?.json().await.unwrap_or_default(),
+            Err(e) => {
+                warn!("Failed to fetch health for Consul service {}: {}", name, e);
+                continue;
+            }
+        };
+
+        for entry in entries {
+            let Some(service) = entry.get("Service") else { continue };
+            let host = service
+                .get("Address")
+                .and_then(|v| v.as_str())
+                .filter(|s| !s.is_empty())
+                .or_else(|| entry.get("Node").and_then(|n| n.get("Address")).and_then(|v| v.as_str()))
+                .unwrap_or("unknown")
+                .to_string();
+            let port = service.get("Port").and_then(|v| v.as_u64()).unwrap_or(0) as u16;
+
+            let checks_passing = entry
+                .get("Checks")
+                .and_then(|c| c.as_array())
+                .map(|checks| checks.iter().all(|c| c.get("Status").and_then(|s| s.as_str()) == Some("passing")))
+                .unwrap_or(true);
+
+            let metadata = ServiceMetadata {
+                host: host.clone(),
+                port,
+                discovered_at: current_timestamp(),
+                last_health_check: current_timestamp(),
+                health_status: if checks_passing { HealthStatus::Healthy } else { HealthStatus::Unhealthy },
+                response_time_ms: None,
+            };
+
+            result.push(LocalhostService { host, port, is_grpc: true, services: Vec::new(), metadata });
+        }
+    }
+
+    Ok(result)
+}
+
+pub(crate) async fn persist_discovered_services(
+    app_handle: &AppHandle,
+    services: &HashMap<String, LocalhostService>,
+) -> anyhow::Result<()> {
+    let path = discovered_services_path(app_handle)?;
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(&path, serde_json::to_vec_pretty(services)?).await?;
+    Ok(())
+}
+
+// Called synchronously from `setup()`, before the async runtime is driving
+// any tasks, so a blocking read is simpler than threading an async load
+// through Tauri's setup hook.
+pub(crate) fn load_discovered_services(app_handle: &AppHandle) -> HashMap<String, LocalhostService> {
+    let Ok(path) = discovered_services_path(app_handle) else { return HashMap::new() };
+    let Ok(contents) = std::fs::read_to_string(&path) else { return HashMap::new() };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+fn discovered_services_path(app_handle: &AppHandle) -> anyhow::Result<PathBuf> {
+    let dir = app_handle.path().app_data_dir()?;
+    Ok(dir.join(DISCOVERED_SERVICES_FILE))
+}
@@ -0,0 +1,319 @@
+// Local HTTP reverse-proxy gateway: fronts already-connected gRPC backends
+// behind a plain HTTP listener, so browser-based tooling that can't speak
+// raw HTTP/2 gRPC can still reach them. Each request path is
+// `/svc/<host>:<port>/<package.Service>/<Method>`; the body is transcoded
+// to protobuf (JSON in, or gRPC-Web framing passed straight through) and
+// dispatched over the existing `connection_pool`, reusing the
+// reflection-derived descriptors for JSON<->protobuf transcoding.
+//
+// Scope: unary and server-streaming methods only. Client-streaming and
+// bidi methods would need a framed, chunked *request* body; that's left
+// for a future pass.
+//
+// Every request other than an OPTIONS preflight (answered with an empty
+// 204 and nothing else) must carry the `X-Gateway-Token` header returned by
+// `start_gateway`, and responses carry no CORS header, so a page merely
+// open in the user's browser can neither trigger nor read a call through
+// this listener — it would need the token, which only this app's own
+// frontend ever sees.
+
+use std::convert::Infallible;
+use std::hash::{Hash, Hasher};
+use std::net::SocketAddr;
+
+use bytes::Bytes;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request as HttpRequest, Response as HttpResponse, Server, StatusCode};
+use prost::Message as _;
+use prost_reflect::DynamicMessage;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use crate::invocation::{build_descriptor_pool, describe_status, parse_method_name, RawCodec};
+use crate::tls;
+use crate::{GrpcResponse, GrpcToolState};
+
+const TOKEN_HEADER: &str = "x-gateway-token";
+
+#[derive(Default)]
+pub(crate) struct Gateway {
+    server: Mutex<Option<(JoinHandle<()>, SocketAddr, String)>>,
+}
+
+#[tauri::command]
+pub(crate) async fn start_gateway(bind_port: u16, state: tauri::State<'_, GrpcToolState>) -> Result<GrpcResponse, String> {
+    let addr = SocketAddr::from(([127, 0, 0, 1], bind_port));
+    let gateway_state = state.inner().clone();
+
+    let make_svc = make_service_fn(move |_conn| {
+        let gateway_state = gateway_state.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |req| handle(req, gateway_state.clone()))) }
+    });
+
+    let server = match Server::try_bind(&addr) {
+        Ok(builder) => builder.serve(make_svc),
+        Err(e) => {
+            return Ok(GrpcResponse {
+                success: false,
+                message: format!("Failed to bind gateway to {}: {}", addr, e),
+                data: None,
+            })
+        }
+    };
+    let bound_addr = server.local_addr();
+    let token = generate_token();
+
+    let handle = tokio::spawn(async move {
+        if let Err(e) = server.await {
+            error!("gRPC gateway server error: {}", e);
+        }
+    });
+
+    if let Some((old, old_addr, _)) = state.gateway.server.lock().await.replace((handle, bound_addr, token.clone())) {
+        info!("Replacing gateway previously bound to {}", old_addr);
+        old.abort();
+    }
+
+    info!("🌐 gRPC gateway listening on {}", bound_addr);
+
+    Ok(GrpcResponse {
+        success: true,
+        message: format!("Gateway listening on {}", bound_addr),
+        data: Some(serde_json::json!({ "address": bound_addr.to_string(), "token": token })),
+    })
+}
+
+#[tauri::command]
+pub(crate) async fn stop_gateway(state: tauri::State<'_, GrpcToolState>) -> Result<GrpcResponse, String> {
+    if let Some((handle, addr, _)) = state.gateway.server.lock().await.take() {
+        handle.abort();
+        info!("🛑 gRPC gateway at {} stopped", addr);
+    }
+
+    Ok(GrpcResponse { success: true, message: "Gateway stopped".to_string(), data: None })
+}
+
+// Not cryptographically strong, but it doesn't need to be: it's a
+// same-machine pairing secret between this process and its own frontend,
+// handed back once over the (already trusted) Tauri IPC bridge and never
+// persisted, not a credential guarding a remote service.
+fn generate_token() -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::time::SystemTime::now().hash(&mut hasher);
+    std::thread::current().id().hash(&mut hasher);
+    let stack_address = &hasher as *const _ as usize;
+    stack_address.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+async fn handle(req: HttpRequest<Body>, state: GrpcToolState) -> Result<HttpResponse<Body>, Infallible> {
+    if req.method() == hyper::Method::OPTIONS {
+        return Ok(HttpResponse::builder().status(StatusCode::NO_CONTENT).body(Body::empty()).unwrap());
+    }
+
+    if !token_is_valid(&req, &state).await {
+        return Ok(text_response(StatusCode::FORBIDDEN, "Missing or invalid X-Gateway-Token"));
+    }
+
+    match route(req, state).await {
+        Ok(resp) => Ok(resp),
+        Err(message) => Ok(text_response(StatusCode::BAD_GATEWAY, &message)),
+    }
+}
+
+async fn token_is_valid(req: &HttpRequest<Body>, state: &GrpcToolState) -> bool {
+    let Some(expected) = state.gateway.server.lock().await.as_ref().map(|(_, _, token)| token.clone()) else {
+        return false;
+    };
+    req.headers()
+        .get(TOKEN_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|provided| provided == expected)
+        .unwrap_or(false)
+}
+
+// Path shape: /svc/<host>:<port>/<package.Service>/<Method>
+async fn route(req: HttpRequest<Body>, state: GrpcToolState) -> Result<HttpResponse<Body>, String> {
+    let path = req.uri().path().to_string();
+    let rest = path.strip_prefix("/svc/").ok_or_else(|| format!("Unknown path: {}", path))?;
+    let (endpoint, method_path) = rest
+        .split_once('/')
+        .ok_or_else(|| "Expected /svc/<host:port>/<service>/<method>".to_string())?;
+
+    // Restrict proxying to endpoints discovery already knows about, so the
+    // gateway can't be used as an open proxy to arbitrary hosts.
+    if !state.localhost_services.lock().await.contains_key(endpoint) {
+        return Err(format!("{} is not a known discovered service; scan for it first", endpoint));
+    }
+
+    let (host, port) = endpoint.split_once(':').ok_or_else(|| format!("Invalid endpoint {}", endpoint))?;
+    let port: u16 = port.parse().map_err(|_| format!("Invalid port in {}", endpoint))?;
+
+    let (service_name, method_name) = parse_method_name(method_path).map_err(|e| e.to_string())?;
+
+    let channel = state
+        .connection_pool
+        .lock()
+        .await
+        .iter()
+        .find(|(k, _)| tls::pool_key_matches(k, host, port))
+        .map(|(_, v)| v.clone())
+        .ok_or_else(|| format!("No open connection to {}; connect_grpc first", endpoint))?;
+
+    let pool = build_descriptor_pool(&state.descriptor_cache).await.map_err(|e| e.to_string())?;
+    let method_desc = pool
+        .get_service_by_name(&service_name)
+        .and_then(|s| s.methods().find(|m| m.name() == method_name))
+        .ok_or_else(|| format!("Method {}/{} not found (run discovery first)", service_name, method_name))?;
+
+    if method_desc.is_client_streaming() {
+        return Err(format!("{}/{} is client-streaming; the gateway only proxies unary and server-streaming methods", service_name, method_name));
+    }
+
+    let is_grpc_web = req
+        .headers()
+        .get(hyper::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.starts_with("application/grpc-web"))
+        .unwrap_or(false);
+
+    let body_bytes = hyper::body::to_bytes(req.into_body()).await.map_err(|e| e.to_string())?;
+
+    let encoded = if is_grpc_web {
+        decode_grpc_web_frame(&body_bytes).ok_or_else(|| "Malformed gRPC-Web frame".to_string())?
+    } else {
+        let json: serde_json::Value = serde_json::from_slice(&body_bytes).map_err(|e| format!("Invalid JSON body: {}", e))?;
+        DynamicMessage::deserialize(method_desc.input(), &json)
+            .map_err(|e| format!("Request body doesn't match {}: {}", method_desc.input().full_name(), e))?
+            .encode_to_vec()
+    };
+
+    let rpc_path = http::uri::PathAndQuery::from_maybe_shared(format!("/{}/{}", service_name, method_name))
+        .map_err(|e| format!("Invalid method path: {}", e))?;
+
+    let mut grpc = tonic::client::Grpc::new(channel);
+    grpc.ready().await.map_err(|e| format!("Channel not ready: {}", e))?;
+
+    if method_desc.is_server_streaming() {
+        let response = grpc
+            .server_streaming(tonic::Request::new(encoded), rpc_path, RawCodec::default())
+            .await
+            .map_err(|status| describe_status(&status))?;
+        Ok(stream_response(response.into_inner(), method_desc.output(), is_grpc_web))
+    } else {
+        let response = grpc
+            .unary(tonic::Request::new(encoded), rpc_path, RawCodec::default())
+            .await
+            .map_err(|status| describe_status(&status))?;
+        let payload = response.into_inner();
+
+        if is_grpc_web {
+            let mut body = encode_grpc_web_frame(0x00, &payload);
+            body.extend(encode_grpc_web_frame(0x80, b"grpc-status: 0\r\n"));
+            Ok(HttpResponse::builder()
+                .status(StatusCode::OK)
+                .header(hyper::header::CONTENT_TYPE, "application/grpc-web+proto")
+                .body(Body::from(body))
+                .unwrap())
+        } else {
+            let message = DynamicMessage::decode(method_desc.output(), payload.as_slice())
+                .map_err(|e| format!("Failed to decode response: {}", e))?;
+            let json = serde_json::to_vec(&message).map_err(|e| e.to_string())?;
+            Ok(HttpResponse::builder()
+                .status(StatusCode::OK)
+                .header(hyper::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json))
+                .unwrap())
+        }
+    }
+}
+
+// A gRPC-Web frame is a 1-byte flag (0x00 data, 0x80 trailers) followed by
+// a 4-byte big-endian length and the payload.
+fn decode_grpc_web_frame(bytes: &[u8]) -> Option<Vec<u8>> {
+    if bytes.len() < 5 {
+        return None;
+    }
+    let len = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]) as usize;
+    bytes.get(5..5 + len).map(|b| b.to_vec())
+}
+
+fn encode_grpc_web_frame(flag: u8, payload: &[u8]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(5 + payload.len());
+    buf.push(flag);
+    buf.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    buf.extend_from_slice(payload);
+    buf
+}
+
+// Streams server-streaming responses as successive frames, followed by a
+// trailers frame (gRPC-Web) once the upstream stream ends. JSON clients get
+// newline-delimited JSON values instead of gRPC-Web framing.
+fn stream_response(
+    mut stream: tonic::Streaming<Vec<u8>>,
+    output_desc: prost_reflect::MessageDescriptor,
+    is_grpc_web: bool,
+) -> HttpResponse<Body> {
+    let (mut sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        loop {
+            match stream.message().await {
+                Ok(Some(bytes)) => {
+                    let frame = if is_grpc_web {
+                        encode_grpc_web_frame(0x00, &bytes)
+                    } else {
+                        match DynamicMessage::decode(output_desc.clone(), bytes.as_slice())
+                            .map_err(|e| e.to_string())
+                            .and_then(|message| serde_json::to_vec(&message).map_err(|e| e.to_string()))
+                        {
+                            Ok(mut json) => {
+                                json.push(b'\n');
+                                json
+                            }
+                            Err(e) => {
+                                warn!("Failed to transcode stream message to JSON: {}", e);
+                                break;
+                            }
+                        }
+                    };
+                    if sender.send_data(Bytes::from(frame)).await.is_err() {
+                        break;
+                    }
+                }
+                Ok(None) => {
+                    if is_grpc_web {
+                        let _ = sender.send_data(Bytes::from(encode_grpc_web_frame(0x80, b"grpc-status: 0\r\n"))).await;
+                    }
+                    break;
+                }
+                Err(status) => {
+                    warn!("Gateway stream failed: {}", status);
+                    if is_grpc_web {
+                        let trailer = format!("grpc-status: {}\r\ngrpc-message: {}\r\n", status.code() as i32, status.message());
+                        let _ = sender.send_data(Bytes::from(encode_grpc_web_frame(0x80, trailer.as_bytes()))).await;
+                    }
+                    break;
+                }
+            }
+        }
+    });
+
+    HttpResponse::builder()
+        .status(StatusCode::OK)
+        .header(
+            hyper::header::CONTENT_TYPE,
+            if is_grpc_web { "application/grpc-web+proto" } else { "application/json" },
+        )
+        .body(body)
+        .unwrap()
+}
+
+fn text_response(status: StatusCode, message: &str) -> HttpResponse<Body> {
+    HttpResponse::builder()
+        .status(status)
+        .header(hyper::header::CONTENT_TYPE, "text/plain")
+        .body(Body::from(message.to_string()))
+        .unwrap()
+}
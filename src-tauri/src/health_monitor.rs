@@ -0,0 +1,163 @@
+// Background health monitoring: periodically re-probes every entry in the
+// discovered-services map on its own tokio task, pushing a
+// `service-health-changed` event to the webview whenever a service's
+// status changes (between Healthy, Unhealthy and Unknown).
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::{current_timestamp, is_grpc_service, GrpcResponse, GrpcToolState, HealthStatus};
+
+pub(crate) const DEFAULT_INTERVAL_SECS: u64 = 30;
+
+// A single bad probe shouldn't flip a service to Unhealthy; this many
+// consecutive failures are required first.
+const UNHEALTHY_THRESHOLD: u32 = 2;
+// After this many consecutive failures, stop probing the endpoint (it's
+// presumed gone) but keep it in the list, marked Unknown.
+const GONE_THRESHOLD: u32 = 5;
+
+const HEALTH_CHANGED_EVENT: &str = "service-health-changed";
+
+// A plain std Mutex, not the async tokio one: every lock here is held only
+// across a quick map/Option op, never across an `.await`, and `spawn` needs
+// to register the new task's handle synchronously (see its comment below).
+#[derive(Default)]
+pub(crate) struct HealthMonitor {
+    task: Mutex<Option<JoinHandle<()>>>,
+    consecutive_failures: Mutex<HashMap<String, u32>>,
+    stopped: Mutex<HashSet<String>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct HealthChangedEvent {
+    endpoint: String,
+    previous_status: HealthStatus,
+    health_status: HealthStatus,
+}
+
+#[tauri::command]
+pub(crate) async fn start_health_monitor(
+    interval_secs: u64,
+    app_handle: AppHandle,
+    state: tauri::State<'_, GrpcToolState>,
+) -> Result<GrpcResponse, String> {
+    spawn(state.inner().clone(), app_handle, interval_secs);
+
+    Ok(GrpcResponse {
+        success: true,
+        message: format!("Health monitor running every {}s", interval_secs.max(1)),
+        data: None,
+    })
+}
+
+#[tauri::command]
+pub(crate) async fn stop_health_monitor(state: tauri::State<'_, GrpcToolState>) -> Result<GrpcResponse, String> {
+    if let Some(handle) = state.health_monitor.task.lock().unwrap().take() {
+        handle.abort();
+        info!("🛑 Health monitor stopped");
+    }
+
+    Ok(GrpcResponse { success: true, message: "Health monitor stopped".to_string(), data: None })
+}
+
+// (Re)starts the monitoring loop, replacing any task already running.
+//
+// Clearing the bookkeeping and registering the new task's `JoinHandle` all
+// happen synchronously, before this function returns. Doing any of that
+// inside a spawned task would let a `stop_health_monitor`
+// call land in the gap between `start_health_monitor` returning and that
+// task actually storing the handle, see `task == None`, do nothing, and
+// lose the race to the deferred start.
+pub(crate) fn spawn(state: GrpcToolState, app_handle: AppHandle, interval_secs: u64) {
+    let monitor = state.health_monitor.clone();
+
+    monitor.consecutive_failures.lock().unwrap().clear();
+    monitor.stopped.lock().unwrap().clear();
+
+    let handle = tokio::spawn(run_loop(state, app_handle, interval_secs.max(1)));
+    if let Some(previous) = monitor.task.lock().unwrap().replace(handle) {
+        previous.abort();
+    }
+}
+
+async fn run_loop(state: GrpcToolState, app_handle: AppHandle, interval_secs: u64) {
+    info!("💓 Health monitor starting, probing every {}s", interval_secs);
+    let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs));
+
+    loop {
+        ticker.tick().await;
+        probe_all(&state, &app_handle).await;
+    }
+}
+
+async fn probe_all(state: &GrpcToolState, app_handle: &AppHandle) {
+    let endpoints: Vec<(String, String, u16)> = state
+        .localhost_services
+        .lock()
+        .await
+        .values()
+        .map(|s| (format!("{}:{}", s.host, s.port), s.host.clone(), s.port))
+        .collect();
+
+    for (endpoint, host, port) in endpoints {
+        if state.health_monitor.stopped.lock().unwrap().contains(&endpoint) {
+            continue;
+        }
+
+        // Reuse TLS material that previously worked for this endpoint (see
+        // `GrpcToolState::tls_configs`), so a TLS-only service isn't probed
+        // in plaintext forever and flapped to Unhealthy/Unknown.
+        let tls = state.tls_configs.lock().await.get(&endpoint).cloned();
+
+        let start = std::time::Instant::now();
+        let is_healthy = is_grpc_service(&host, port, tls.as_ref()).await.unwrap_or(false);
+        let response_time_ms = start.elapsed().as_millis() as u64;
+
+        let mut failures = state.health_monitor.consecutive_failures.lock().unwrap();
+        let count = failures.entry(endpoint.clone()).or_insert(0);
+
+        let new_status = if is_healthy {
+            *count = 0;
+            HealthStatus::Healthy
+        } else {
+            *count += 1;
+            if *count >= GONE_THRESHOLD {
+                state.health_monitor.stopped.lock().unwrap().insert(endpoint.clone());
+                warn!("Service {} unreachable for {} consecutive checks, no longer probing", endpoint, count);
+                HealthStatus::Unknown
+            } else if *count >= UNHEALTHY_THRESHOLD {
+                HealthStatus::Unhealthy
+            } else {
+                // Below the flap threshold: leave the last-known status as-is.
+                drop(failures);
+                continue;
+            }
+        };
+        drop(failures);
+
+        let mut services = state.localhost_services.lock().await;
+        let Some(service) = services.get_mut(&endpoint) else { continue };
+
+        let previous_status = service.metadata.health_status.clone();
+        service.metadata.health_status = new_status.clone();
+        service.metadata.last_health_check = current_timestamp();
+        service.metadata.response_time_ms = Some(response_time_ms);
+        drop(services);
+
+        debug!("Probed {}: {:?} ({}ms)", endpoint, new_status, response_time_ms);
+
+        if std::mem::discriminant(&previous_status) != std::mem::discriminant(&new_status) {
+            let _ = app_handle.emit(
+                HEALTH_CHANGED_EVENT,
+                &HealthChangedEvent { endpoint, previous_status, health_status: new_status },
+            );
+        }
+    }
+}
@@ -0,0 +1,305 @@
+// Dynamic, grpcurl-style method invocation: given a fully-qualified method
+// name and a plain JSON body, resolve the method's input/output message
+// descriptors from the reflection-derived descriptor cache, transcode the
+// JSON into protobuf on the wire, and transcode the response back to JSON
+// for the frontend.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use bytes::{Buf, BufMut};
+use prost::Message as _;
+use prost_reflect::{DescriptorPool, DynamicMessage};
+use prost_types::{FileDescriptorProto, FileDescriptorSet};
+use tauri::{AppHandle, Emitter};
+use tokio::sync::Mutex;
+use tonic::codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder};
+use tonic::transport::Channel;
+use tonic::Request;
+use tracing::{error, info};
+
+use crate::tls;
+use crate::{GrpcResponse, GrpcToolState};
+
+// Incremental payload pushed to the frontend for server-streaming and
+// bidi-streaming calls. Unary and client-streaming calls never emit these;
+// their single response comes back as `GrpcResponse.data` instead.
+#[derive(Debug, serde::Serialize)]
+struct StreamEvent<'a> {
+    method: &'a str,
+    message: Option<serde_json::Value>,
+    done: bool,
+    error: Option<String>,
+}
+
+const STREAM_EVENT: &str = "grpc-method-stream";
+
+#[tauri::command]
+pub(crate) async fn call_method(
+    host: String,
+    port: u16,
+    method: String,
+    request: serde_json::Value,
+    app_handle: AppHandle,
+    state: tauri::State<'_, GrpcToolState>,
+) -> Result<GrpcResponse, String> {
+    let (service_name, method_name) = match parse_method_name(&method) {
+        Ok(parts) => parts,
+        Err(e) => return Ok(invocation_error(e.to_string())),
+    };
+
+    // The pool key also encodes the connection's TLS identity (see
+    // `tls::pool_key`), so match on the host:port prefix rather than an
+    // exact key.
+    let endpoint = format!("{}:{}", host, port);
+    let channel = match state
+        .connection_pool
+        .lock()
+        .await
+        .iter()
+        .find(|(k, _)| tls::pool_key_matches(k, &host, port))
+        .map(|(_, v)| v.clone())
+    {
+        Some(channel) => channel,
+        None => return Ok(invocation_error(format!("No open connection to {}", endpoint))),
+    };
+
+    let pool = match build_descriptor_pool(&state.descriptor_cache).await {
+        Ok(pool) => pool,
+        Err(e) => return Ok(invocation_error(format!("Failed to build descriptor pool: {}", e))),
+    };
+
+    let method_desc = match pool
+        .get_service_by_name(&service_name)
+        .and_then(|service| service.methods().find(|m| m.name() == method_name))
+    {
+        Some(m) => m,
+        None => {
+            return Ok(invocation_error(format!(
+                "Method {}/{} not found (run discovery first)",
+                service_name, method_name
+            )))
+        }
+    };
+
+    let input = match DynamicMessage::deserialize(method_desc.input(), &request) {
+        Ok(msg) => msg,
+        Err(e) => return Ok(invocation_error(format!("Request body doesn't match {}: {}", method_desc.input().full_name(), e))),
+    };
+
+    let path = match http::uri::PathAndQuery::from_maybe_shared(format!("/{}/{}", service_name, method_name)) {
+        Ok(path) => path,
+        Err(e) => return Ok(invocation_error(format!("Invalid method path: {}", e))),
+    };
+
+    let mut grpc = tonic::client::Grpc::new(channel);
+    if let Err(e) = grpc.ready().await {
+        return Ok(invocation_error(format!("Channel not ready: {}", e)));
+    }
+
+    let output_desc = method_desc.output();
+    let encoded = input.encode_to_vec();
+
+    info!("📡 Invoking {} on {}", method, endpoint);
+
+    match (method_desc.is_client_streaming(), method_desc.is_server_streaming()) {
+        (false, false) => {
+            match grpc.unary(Request::new(encoded), path, RawCodec::default()).await {
+                Ok(response) => match DynamicMessage::decode(output_desc, response.into_inner().as_slice()) {
+                    Ok(message) => Ok(GrpcResponse {
+                        success: true,
+                        message: format!("Called {}", method),
+                        data: Some(serde_json::to_value(&message).unwrap_or(serde_json::Value::Null)),
+                    }),
+                    Err(e) => Ok(invocation_error(format!("Failed to decode response: {}", e))),
+                },
+                Err(status) => Ok(invocation_error(describe_status(&status))),
+            }
+        }
+        (true, false) => {
+            let stream = tokio_stream::once(encoded);
+            match grpc.client_streaming(Request::new(stream), path, RawCodec::default()).await {
+                Ok(response) => match DynamicMessage::decode(output_desc, response.into_inner().as_slice()) {
+                    Ok(message) => Ok(GrpcResponse {
+                        success: true,
+                        message: format!("Called {}", method),
+                        data: Some(serde_json::to_value(&message).unwrap_or(serde_json::Value::Null)),
+                    }),
+                    Err(e) => Ok(invocation_error(format!("Failed to decode response: {}", e))),
+                },
+                Err(status) => Ok(invocation_error(describe_status(&status))),
+            }
+        }
+        (false, true) => {
+            match grpc.server_streaming(Request::new(encoded), path, RawCodec::default()).await {
+                Ok(response) => {
+                    stream_responses(response.into_inner(), output_desc, app_handle, method.clone()).await;
+                    Ok(GrpcResponse {
+                        success: true,
+                        message: format!("Streaming {} via '{}' events", method, STREAM_EVENT),
+                        data: None,
+                    })
+                }
+                Err(status) => Ok(invocation_error(describe_status(&status))),
+            }
+        }
+        (true, true) => {
+            let stream = tokio_stream::once(encoded);
+            match grpc.streaming(Request::new(stream), path, RawCodec::default()).await {
+                Ok(response) => {
+                    stream_responses(response.into_inner(), output_desc, app_handle, method.clone()).await;
+                    Ok(GrpcResponse {
+                        success: true,
+                        message: format!("Streaming {} via '{}' events", method, STREAM_EVENT),
+                        data: None,
+                    })
+                }
+                Err(status) => Ok(invocation_error(describe_status(&status))),
+            }
+        }
+    }
+}
+
+// Drains a streaming gRPC response, emitting one `StreamEvent` per message
+// plus a final `done: true` event once the stream ends (successfully or not).
+async fn stream_responses(
+    mut stream: tonic::Streaming<Vec<u8>>,
+    output_desc: prost_reflect::MessageDescriptor,
+    app_handle: AppHandle,
+    method: String,
+) {
+    loop {
+        match stream.message().await {
+            Ok(Some(bytes)) => {
+                let event = match DynamicMessage::decode(output_desc.clone(), bytes.as_slice()) {
+                    Ok(message) => StreamEvent {
+                        method: &method,
+                        message: Some(serde_json::to_value(&message).unwrap_or(serde_json::Value::Null)),
+                        done: false,
+                        error: None,
+                    },
+                    Err(e) => StreamEvent {
+                        method: &method,
+                        message: None,
+                        done: false,
+                        error: Some(format!("Failed to decode stream message: {}", e)),
+                    },
+                };
+                let _ = app_handle.emit(STREAM_EVENT, &event);
+            }
+            Ok(None) => {
+                let _ = app_handle.emit(
+                    STREAM_EVENT,
+                    &StreamEvent { method: &method, message: None, done: true, error: None },
+                );
+                break;
+            }
+            Err(status) => {
+                error!("❌ Stream {} failed: {}", method, status);
+                let _ = app_handle.emit(
+                    STREAM_EVENT,
+                    &StreamEvent { method: &method, message: None, done: true, error: Some(describe_status(&status)) },
+                );
+                break;
+            }
+        }
+    }
+}
+
+fn invocation_error(message: String) -> GrpcResponse {
+    GrpcResponse { success: false, message, data: None }
+}
+
+// Formats the status code and message and folds in any trailing metadata
+// (other than grpc-status/grpc-message), so the frontend sees more than
+// "unknown error". Every status, whatever its cause, gets the same
+// "gRPC call failed" prefix.
+pub(crate) fn describe_status(status: &tonic::Status) -> String {
+    let details = status.message();
+    let mut message = if details.is_empty() {
+        format!("gRPC call failed: {:?}", status.code())
+    } else {
+        format!("gRPC call failed: {:?}: {}", status.code(), details)
+    };
+
+    let trailers: Vec<String> = status
+        .metadata()
+        .iter()
+        .filter_map(|kv| match kv {
+            tonic::metadata::KeyAndValueRef::Ascii(key, value) => {
+                let key = key.as_str();
+                if key == "grpc-status" || key == "grpc-message" {
+                    return None;
+                }
+                value.to_str().ok().map(|v| format!("{}={}", key, v))
+            }
+            tonic::metadata::KeyAndValueRef::Binary(key, value) => {
+                Some(format!("{}=<{} byte(s)>", key.as_str(), value.len()))
+            }
+        })
+        .collect();
+
+    if !trailers.is_empty() {
+        message.push_str(&format!(" ({})", trailers.join(", ")));
+    }
+
+    message
+}
+
+pub(crate) fn parse_method_name(method: &str) -> anyhow::Result<(String, String)> {
+    let method = method.trim_start_matches('/');
+    let (service, name) = method
+        .rsplit_once('/')
+        .ok_or_else(|| anyhow::anyhow!("method must be in `package.Service/Method` form, got `{}`", method))?;
+    Ok((service.to_string(), name.to_string()))
+}
+
+pub(crate) async fn build_descriptor_pool(cache: &Arc<Mutex<HashMap<String, FileDescriptorProto>>>) -> anyhow::Result<DescriptorPool> {
+    let files: Vec<FileDescriptorProto> = cache.lock().await.values().cloned().collect();
+    let set = FileDescriptorSet { file: files };
+    DescriptorPool::from_file_descriptor_set(set).map_err(|e| anyhow::anyhow!("{}", e))
+}
+
+// A passthrough codec: the bytes handed to it are already encoded protobuf
+// (from a `DynamicMessage`), so encode/decode just move bytes in and out of
+// tonic's length-delimited framing without a concrete message type.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        RawCodec
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        RawCodec
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        if !src.has_remaining() {
+            return Ok(None);
+        }
+        let len = src.remaining();
+        Ok(Some(src.copy_to_bytes(len).to_vec()))
+    }
+}
@@ -14,10 +14,25 @@ use tonic_reflection::pb::v1::server_reflection_client::ServerReflectionClient;
 use tonic_reflection::pb::v1::{ServerReflectionRequest, ServerReflectionResponse};
 use tonic_reflection::pb::v1::server_reflection_request::MessageRequest;
 use tonic_reflection::pb::v1::server_reflection_response::MessageResponse;
+use prost::Message as _;
+use prost_types::FileDescriptorProto;
 use tracing::{info, warn, error, debug};
 use anyhow::Result;
 use thiserror::Error;
 
+mod invocation;
+use invocation::call_method;
+mod sockets;
+use sockets::scan_listening_sockets;
+mod consul;
+use consul::{configure_consul, refresh_from_consul, ConsulConfig};
+mod tls;
+use tls::TlsConfig;
+mod health_monitor;
+use health_monitor::{start_health_monitor, stop_health_monitor};
+mod gateway;
+use gateway::{start_gateway, stop_gateway};
+
 #[derive(Error, Debug)]
 pub enum GrpcToolError {
     #[error("Connection failed: {0}")]
@@ -29,10 +44,10 @@ pub enum GrpcToolError {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct GrpcResponse {
-    success: bool,
-    message: String,
-    data: Option<serde_json::Value>,
+pub(crate) struct GrpcResponse {
+    pub(crate) success: bool,
+    pub(crate) message: String,
+    pub(crate) data: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,29 +67,29 @@ struct GrpcMethod {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct ServiceMetadata {
-    host: String,
-    port: u16,
-    discovered_at: u64,
-    last_health_check: u64,
-    health_status: HealthStatus,
-    response_time_ms: Option<u64>,
+pub(crate) struct ServiceMetadata {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) discovered_at: u64,
+    pub(crate) last_health_check: u64,
+    pub(crate) health_status: HealthStatus,
+    pub(crate) response_time_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-enum HealthStatus {
+pub(crate) enum HealthStatus {
     Healthy,
     Unhealthy,
     Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct LocalhostService {
-    host: String,
-    port: u16,
-    is_grpc: bool,
-    services: Vec<GrpcService>,
-    metadata: ServiceMetadata,
+pub(crate) struct LocalhostService {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) is_grpc: bool,
+    pub(crate) services: Vec<GrpcService>,
+    pub(crate) metadata: ServiceMetadata,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,6 +98,13 @@ struct GrpcConnection {
     port: u16,
     use_tls: bool,
     connected: bool,
+    // Mutual TLS / custom CA material, present only when `use_tls` was
+    // paired with a `TlsConfig` on `connect_grpc`. Contains the client
+    // private key in plaintext, so it is never serialized back out over
+    // the IPC bridge (see `get_connection_status`). `connect_grpc` records
+    // it here; nothing reads it back.
+    #[serde(skip_serializing)]
+    tls: Option<TlsConfig>,
 }
 
 impl Default for GrpcConnection {
@@ -92,21 +114,38 @@ impl Default for GrpcConnection {
             port: 9090,
             use_tls: false,
             connected: false,
+            tls: None,
         }
     }
 }
 
-// Enhanced application state for localhost service discovery
-struct GrpcToolState {
+// Enhanced application state for localhost service discovery. Every field
+// is an `Arc`, so the state as a whole is cheaply `Clone`-able into
+// background tasks (e.g. the health monitor) without a lock.
+#[derive(Clone)]
+pub(crate) struct GrpcToolState {
     connection: Arc<Mutex<GrpcConnection>>,
     services: Arc<Mutex<Vec<GrpcService>>>,
     client: Arc<Mutex<Option<ServerReflectionClient<Channel>>>>,
-    localhost_services: Arc<Mutex<HashMap<String, LocalhostService>>>,
-    connection_pool: Arc<Mutex<HashMap<String, Channel>>>,
+    pub(crate) localhost_services: Arc<Mutex<HashMap<String, LocalhostService>>>,
+    pub(crate) connection_pool: Arc<Mutex<HashMap<String, Channel>>>,
+    // Decoded FileDescriptorProto cache, keyed by the proto file's own `name`,
+    // so transitive dependencies pulled in while resolving one service aren't
+    // re-fetched when another service references the same file.
+    pub(crate) descriptor_cache: Arc<Mutex<HashMap<String, FileDescriptorProto>>>,
+    // TLS material last used to successfully `connect_grpc` each endpoint,
+    // keyed by plain "host:port" (no TLS identity suffix). Scanning and the
+    // health monitor consult this so a known TLS-only endpoint isn't probed
+    // in plaintext forever; an endpoint never connected with TLS has no
+    // entry here and is scanned/probed in plaintext, same as before.
+    pub(crate) tls_configs: Arc<Mutex<HashMap<String, TlsConfig>>>,
+    pub(crate) consul_config: Arc<Mutex<Option<ConsulConfig>>>,
+    pub(crate) health_monitor: Arc<health_monitor::HealthMonitor>,
+    pub(crate) gateway: Arc<gateway::Gateway>,
 }
 
 // Common gRPC ports to scan on localhost
-const COMMON_GRPC_PORTS: &[u16] = &[
+pub(crate) const COMMON_GRPC_PORTS: &[u16] = &[
     9090, 8080, 50051, 50052, 50053, 8081, 8082, 8083, 8084, 8085,
     9091, 9092, 9093, 9094, 9095, 3000, 3001, 3002, 4000, 4001,
     5000, 5001, 5002, 6000, 6001, 7000, 7001, 8000, 8001, 8002,
@@ -114,6 +153,7 @@ const COMMON_GRPC_PORTS: &[u16] = &[
 
 #[tauri::command]
 async fn scan_localhost_services(
+    app_handle: tauri::AppHandle,
     state: tauri::State<'_, GrpcToolState>,
 ) -> Result<GrpcResponse, String> {
     info!("🔍 Starting localhost gRPC service discovery...");
@@ -124,10 +164,11 @@ async fn scan_localhost_services(
     for &port in COMMON_GRPC_PORTS {
         let host = "localhost".to_string();
         let endpoint = format!("{}:{}", host, port);
-        
+
         debug!("Scanning port {}", port);
-        
-        match scan_port(&host, port).await {
+
+        let tls = state.tls_configs.lock().await.get(&endpoint).cloned();
+        match scan_port(&host, port, tls.as_ref()).await {
             Ok(is_grpc) => {
                 if is_grpc {
                     info!("✅ Found gRPC service at {}", endpoint);
@@ -165,9 +206,17 @@ async fn scan_localhost_services(
         }
     }
     
-    // Update state
-    *state.localhost_services.lock().await = discovered_services.clone();
-    
+    // Merge into the discovered-services map rather than replacing it, so
+    // results from other discovery sources (netstat, Consul) aren't lost.
+    let mut services = state.localhost_services.lock().await;
+    services.extend(discovered_services.clone());
+    let merged = services.clone();
+    drop(services);
+
+    if let Err(e) = consul::persist_discovered_services(&app_handle, &merged).await {
+        warn!("Failed to persist discovered services: {}", e);
+    }
+
     info!("🎯 Discovery complete: found {} gRPC services", discovered_services.len());
     
     Ok(GrpcResponse {
@@ -182,16 +231,16 @@ async fn scan_localhost_services(
     })
 }
 
-async fn scan_port(host: &str, port: u16) -> Result<bool> {
+async fn scan_port(host: &str, port: u16, tls: Option<&TlsConfig>) -> Result<bool> {
     // First, check if port is open
     let addr = format!("{}:{}", host, port);
-    
+
     match timeout(Duration::from_millis(100), TcpStream::connect(&addr)).await {
         Ok(Ok(_stream)) => {
             debug!("Port {} is open, checking if it's gRPC", port);
-            
+
             // Try to establish a gRPC connection
-            match is_grpc_service(host, port).await {
+            match is_grpc_service(host, port, tls).await {
                 Ok(is_grpc) => Ok(is_grpc),
                 Err(_) => Ok(false), // Port is open but not gRPC
             }
@@ -200,11 +249,20 @@ async fn scan_port(host: &str, port: u16) -> Result<bool> {
     }
 }
 
-async fn is_grpc_service(host: &str, port: u16) -> Result<bool> {
-    let endpoint = format!("http://{}:{}", host, port);
-    
+pub(crate) async fn is_grpc_service(host: &str, port: u16, tls: Option<&TlsConfig>) -> Result<bool> {
+    let scheme = if tls.is_some() { "https" } else { "http" };
+    let endpoint = format!("{}://{}:{}", scheme, host, port);
+
     match Channel::from_shared(endpoint) {
         Ok(channel) => {
+            let channel = match tls {
+                Some(tls) => match tls::build_client_tls_config(tls).and_then(|cfg| Ok(channel.tls_config(cfg)?)) {
+                    Ok(channel) => channel,
+                    Err(_) => return Ok(false),
+                },
+                None => channel,
+            };
+
             match timeout(Duration::from_millis(500), channel.connect()).await {
                 Ok(Ok(conn)) => {
                     // Try to create a reflection client
@@ -236,7 +294,7 @@ async fn is_grpc_service(host: &str, port: u16) -> Result<bool> {
     }
 }
 
-fn current_timestamp() -> u64 {
+pub(crate) fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or_default()
@@ -256,6 +314,7 @@ async fn connect_grpc(
     host: String,
     port: u16,
     use_tls: bool,
+    tls: Option<TlsConfig>,
     state: tauri::State<'_, GrpcToolState>,
 ) -> Result<GrpcResponse, String> {
     let endpoint = if use_tls {
@@ -266,22 +325,55 @@ async fn connect_grpc(
 
     info!("🔗 Connecting to gRPC server at {}", endpoint);
 
+    let tls = if use_tls { tls } else { None };
+
     match Channel::from_shared(endpoint.clone()) {
-        Ok(channel) => {
-            match channel.connect().await {
+        Ok(builder) => {
+            let builder = if let Some(tls_config) = &tls {
+                match tls::build_client_tls_config(tls_config).and_then(|cfg| Ok(builder.tls_config(cfg)?)) {
+                    Ok(builder) => builder,
+                    Err(e) => {
+                        error!("❌ Invalid TLS configuration for {}: {}", endpoint, e);
+                        return Ok(GrpcResponse {
+                            success: false,
+                            message: format!("TLS handshake/verification failed: invalid TLS configuration: {}", e),
+                            data: None,
+                        });
+                    }
+                }
+            } else {
+                builder
+            };
+
+            match builder.connect().await {
                 Ok(conn) => {
                     let client = ServerReflectionClient::new(conn.clone());
                     *state.client.lock().await = Some(client);
-                    
-                    // Store connection in pool
-                    let pool_key = format!("{}:{}", host, port);
+
+                    // Store connection in pool, keyed so a plaintext and an
+                    // mTLS connection to the same host:port don't collide.
+                    let pool_key = tls::pool_key(&host, port, tls.as_ref());
                     state.connection_pool.lock().await.insert(pool_key, conn);
-                    
+
+                    // Remember (or forget) the TLS material that worked for
+                    // this endpoint, so scanning and the health monitor can
+                    // reuse it instead of always probing in plaintext.
+                    let endpoint_key = format!("{}:{}", host, port);
+                    match &tls {
+                        Some(tls) => {
+                            state.tls_configs.lock().await.insert(endpoint_key, tls.clone());
+                        }
+                        None => {
+                            state.tls_configs.lock().await.remove(&endpoint_key);
+                        }
+                    }
+
                     let mut connection = state.connection.lock().await;
                     connection.host = host;
                     connection.port = port;
                     connection.use_tls = use_tls;
                     connection.connected = true;
+                    connection.tls = tls;
 
                     info!("✅ Connected to gRPC server at {}", endpoint);
 
@@ -295,10 +387,11 @@ async fn connect_grpc(
                     })
                 }
                 Err(e) => {
-                    error!("❌ Failed to connect to {}: {}", endpoint, e);
+                    let message = tls::describe_connect_error(&e);
+                    error!("❌ Failed to connect to {}: {}", endpoint, message);
                     Ok(GrpcResponse {
                         success: false,
-                        message: format!("Failed to connect to gRPC server: {}", e),
+                        message,
                         data: None,
                     })
                 }
@@ -312,6 +405,156 @@ async fn connect_grpc(
     }
 }
 
+// Sends a single reflection request and returns the first message the server
+// replies with. The reflection RPC is bidi-streaming, but every request this
+// tool makes is a single request/single response exchange, so the stream
+// plumbing is collapsed into one call.
+async fn reflection_request(
+    client: &mut ServerReflectionClient<Channel>,
+    message_request: MessageRequest,
+) -> Result<Option<MessageResponse>> {
+    let (tx, rx) = tokio::sync::mpsc::channel(1);
+
+    let request = ServerReflectionRequest {
+        host: "".to_string(),
+        message_request: Some(message_request),
+    };
+
+    tx.send(request).await.map_err(|e| anyhow::anyhow!("failed to send reflection request: {}", e))?;
+    drop(tx);
+
+    let request_stream = tokio_stream::wrappers::ReceiverStream::new(rx);
+    let mut stream = client.server_reflection_info(request_stream).await?.into_inner();
+
+    Ok(stream.message().await?.and_then(|msg| msg.message_response))
+}
+
+// Fetches the FileDescriptorProto containing `symbol` (a fully-qualified
+// service or message name) and everything it transitively depends on,
+// decoding each one with prost and populating `cache` keyed by file name.
+// Already-cached files are skipped, so a dependency shared by multiple
+// services is only ever fetched once per connection.
+async fn fetch_descriptors_for_symbol(
+    client: &mut ServerReflectionClient<Channel>,
+    cache: &Arc<Mutex<HashMap<String, FileDescriptorProto>>>,
+    symbol: &str,
+) -> Result<Option<FileDescriptorProto>> {
+    let response = reflection_request(client, MessageRequest::FileContainingSymbol(symbol.to_string())).await?;
+
+    let proto_bytes = match response {
+        Some(MessageResponse::FileDescriptorResponse(resp)) => resp.file_descriptor_proto,
+        Some(MessageResponse::ErrorResponse(e)) => {
+            warn!("Reflection error resolving symbol {}: {:?}", symbol, e);
+            return Ok(None);
+        }
+        _ => return Ok(None),
+    };
+
+    let mut root = None;
+    for bytes in proto_bytes {
+        let file = FileDescriptorProto::decode(bytes.as_slice())?;
+        let name = file.name.clone().unwrap_or_default();
+
+        if root.is_none() {
+            root = Some(file.clone());
+        }
+
+        if !cache.lock().await.contains_key(&name) {
+            cache.lock().await.insert(name, file.clone());
+        }
+    }
+
+    if let Some(root) = &root {
+        fetch_missing_dependencies(client, cache, &root.dependency).await?;
+    }
+
+    Ok(root)
+}
+
+// Walks `dependency[]` file names, fetching (and recursively expanding) any
+// that aren't already in `cache`.
+async fn fetch_missing_dependencies(
+    client: &mut ServerReflectionClient<Channel>,
+    cache: &Arc<Mutex<HashMap<String, FileDescriptorProto>>>,
+    dependencies: &[String],
+) -> Result<()> {
+    for dep in dependencies {
+        if cache.lock().await.contains_key(dep) {
+            continue;
+        }
+
+        let response = reflection_request(client, MessageRequest::FileByFilename(dep.clone())).await?;
+        let proto_bytes = match response {
+            Some(MessageResponse::FileDescriptorResponse(resp)) => resp.file_descriptor_proto,
+            Some(MessageResponse::ErrorResponse(e)) => {
+                warn!("Reflection error resolving dependency {}: {:?}", dep, e);
+                continue;
+            }
+            _ => continue,
+        };
+
+        let mut nested_deps = Vec::new();
+        for bytes in proto_bytes {
+            let file = FileDescriptorProto::decode(bytes.as_slice())?;
+            nested_deps.extend(file.dependency.clone());
+            cache.lock().await.insert(file.name.clone().unwrap_or_default(), file);
+        }
+
+        // Dependencies can themselves have dependencies; recurse into those
+        // not already cached before moving on to the next sibling.
+        Box::pin(fetch_missing_dependencies(client, cache, &nested_deps)).await?;
+    }
+
+    Ok(())
+}
+
+// Resolves the RPC methods for `service_name` (as reported by ListServices)
+// via FileContainingSymbol reflection. Returns an empty list, rather than an
+// error, when the server supports reflection overall but can't resolve this
+// particular symbol, so one bad service doesn't fail the whole discovery.
+async fn fetch_service_methods(
+    client: &mut ServerReflectionClient<Channel>,
+    cache: &Arc<Mutex<HashMap<String, FileDescriptorProto>>>,
+    service_name: &str,
+) -> Vec<GrpcMethod> {
+    let file = match fetch_descriptors_for_symbol(client, cache, service_name).await {
+        Ok(Some(file)) => file,
+        Ok(None) => return Vec::new(),
+        Err(e) => {
+            warn!("Failed to fetch descriptors for service {}: {}", service_name, e);
+            return Vec::new();
+        }
+    };
+
+    let package = file.package.clone().unwrap_or_default();
+    let service = file.service.iter().find(|s| {
+        let fqn = if package.is_empty() {
+            s.name.clone().unwrap_or_default()
+        } else {
+            format!("{}.{}", package, s.name.clone().unwrap_or_default())
+        };
+        fqn == service_name
+    });
+
+    match service {
+        Some(service) => service
+            .method
+            .iter()
+            .map(|m| GrpcMethod {
+                name: m.name.clone().unwrap_or_default(),
+                input_type: m.input_type.clone().unwrap_or_default(),
+                output_type: m.output_type.clone().unwrap_or_default(),
+                client_streaming: m.client_streaming.unwrap_or(false),
+                server_streaming: m.server_streaming.unwrap_or(false),
+            })
+            .collect(),
+        None => {
+            warn!("Service {} not found in its own descriptor file", service_name);
+            Vec::new()
+        }
+    }
+}
+
 #[tauri::command]
 async fn discover_services(state: tauri::State<'_, GrpcToolState>) -> Result<GrpcResponse, String> {
     let client_guard = state.client.lock().await;
@@ -360,10 +603,14 @@ async fn discover_services(state: tauri::State<'_, GrpcToolState>) -> Result<Grp
                                 health_status: HealthStatus::Healthy,
                                 response_time_ms: None,
                             };
+                            drop(connection);
+
+                            let methods = fetch_service_methods(&mut client, &state.descriptor_cache, &service.name).await;
+                            debug!("Resolved {} method(s) for service {}", methods.len(), service.name);
 
                             services.push(GrpcService {
                                 name: service.name,
-                                methods: Vec::new(), // TODO: Implement method discovery
+                                methods,
                                 metadata,
                             });
                         }
@@ -440,7 +687,9 @@ async fn health_check_service(
 ) -> Result<GrpcResponse, String> {
     let start_time = std::time::Instant::now();
 
-    match scan_port(&host, port).await {
+    let endpoint_key = format!("{}:{}", host, port);
+    let tls = state.tls_configs.lock().await.get(&endpoint_key).cloned();
+    match scan_port(&host, port, tls.as_ref()).await {
         Ok(is_healthy) => {
             let response_time = start_time.elapsed().as_millis() as u64;
 
@@ -513,6 +762,11 @@ fn main() {
         client: Arc::new(Mutex::new(None)),
         localhost_services: Arc::new(Mutex::new(HashMap::new())),
         connection_pool: Arc::new(Mutex::new(HashMap::new())),
+        descriptor_cache: Arc::new(Mutex::new(HashMap::new())),
+        tls_configs: Arc::new(Mutex::new(HashMap::new())),
+        consul_config: Arc::new(Mutex::new(None)),
+        health_monitor: Arc::new(health_monitor::HealthMonitor::default()),
+        gateway: Arc::new(gateway::Gateway::default()),
     };
 
     tauri::Builder::default()
@@ -527,6 +781,14 @@ fn main() {
             get_connection_status,
             get_services,
             health_check_service,
+            call_method,
+            scan_listening_sockets,
+            configure_consul,
+            refresh_from_consul,
+            start_health_monitor,
+            stop_health_monitor,
+            start_gateway,
+            stop_gateway,
             minimize_window,
             maximize_window,
             close_window,
@@ -535,6 +797,15 @@ fn main() {
         .setup(|app| {
             info!("🚀 gRPC Tool with Localhost Discovery is starting...");
 
+            // Show the last-known service inventory immediately, before any
+            // live scan (port, socket, or Consul) has had a chance to run.
+            let app_handle = app.handle().clone();
+            let restored = consul::load_discovered_services(&app_handle);
+            if !restored.is_empty() {
+                info!("📂 Restored {} previously discovered service(s) from disk", restored.len());
+                *app.state::<GrpcToolState>().localhost_services.blocking_lock() = restored;
+            }
+
             // Get the main window
             let window = app.get_webview_window("main").unwrap();
 
@@ -554,6 +825,11 @@ fn main() {
                 }
             });
 
+            // Start background health monitoring at a sane default interval;
+            // `start_health_monitor`/`stop_health_monitor` let the frontend
+            // retune or disable it later.
+            health_monitor::spawn(app.state::<GrpcToolState>().inner().clone(), app_handle.clone(), health_monitor::DEFAULT_INTERVAL_SECS);
+
             info!("✅ gRPC Tool setup complete!");
             info!("🔍 Ready for localhost service discovery");
             Ok(())
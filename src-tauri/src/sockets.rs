@@ -0,0 +1,185 @@
+// Socket-enumeration-based discovery: instead of guessing through
+// `COMMON_GRPC_PORTS`, ask the OS (via `netstat2`) which TCP sockets are
+// actually listening and only probe those for gRPC.
+
+use std::collections::{HashMap, HashSet};
+
+use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo, TcpState};
+use serde::Deserialize;
+use tracing::{debug, info, warn};
+
+use crate::{
+    consul, current_timestamp, is_grpc_service, GrpcResponse, GrpcToolState, HealthStatus, LocalhostService,
+    ServiceMetadata, COMMON_GRPC_PORTS,
+};
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ScanMode {
+    Netstat,
+    PortScan,
+}
+
+impl Default for ScanMode {
+    fn default() -> Self {
+        ScanMode::Netstat
+    }
+}
+
+struct ListeningSocket {
+    port: u16,
+    pid: Option<u32>,
+    process_name: Option<String>,
+}
+
+#[tauri::command]
+pub(crate) async fn scan_listening_sockets(
+    mode: Option<ScanMode>,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, GrpcToolState>,
+) -> Result<GrpcResponse, String> {
+    let mode = mode.unwrap_or_default();
+
+    let candidates = match mode {
+        ScanMode::Netstat => match list_listening_sockets() {
+            Ok(sockets) => sockets,
+            Err(e) => {
+                warn!("Socket enumeration unavailable ({}), falling back to hardcoded port scan", e);
+                fallback_candidates()
+            }
+        },
+        ScanMode::PortScan => fallback_candidates(),
+    };
+
+    info!("🔍 Probing {} listening socket(s) for gRPC...", candidates.len());
+
+    let mut discovered = HashMap::new();
+    let mut scan_results = Vec::new();
+
+    for socket in &candidates {
+        let host = "localhost".to_string();
+        let endpoint = format!("{}:{}", host, socket.port);
+
+        // Reuse TLS material that previously worked for this endpoint (see
+        // `GrpcToolState::tls_configs`), so a TLS-only service that's
+        // already been connected once doesn't get reported as "not gRPC".
+        let tls = state.tls_configs.lock().await.get(&endpoint).cloned();
+        match is_grpc_service(&host, socket.port, tls.as_ref()).await {
+            Ok(true) => {
+                info!(
+                    "✅ Found gRPC service at {} ({})",
+                    endpoint,
+                    socket.process_name.as_deref().unwrap_or("unknown process")
+                );
+
+                let metadata = ServiceMetadata {
+                    host: host.clone(),
+                    port: socket.port,
+                    discovered_at: current_timestamp(),
+                    last_health_check: current_timestamp(),
+                    health_status: HealthStatus::Healthy,
+                    response_time_ms: None,
+                };
+
+                discovered.insert(
+                    endpoint.clone(),
+                    LocalhostService {
+                        host,
+                        port: socket.port,
+                        is_grpc: true,
+                        services: Vec::new(),
+                        metadata,
+                    },
+                );
+
+                scan_results.push(serde_json::json!({
+                    "endpoint": endpoint,
+                    "status": "grpc_detected",
+                    "port": socket.port,
+                    "pid": socket.pid,
+                    "process_name": socket.process_name,
+                }));
+            }
+            Ok(false) => debug!("Port {} is listening but not gRPC", socket.port),
+            Err(e) => debug!("Port {} probe failed: {}", socket.port, e),
+        }
+    }
+
+    let mut services = state.localhost_services.lock().await;
+    services.extend(discovered.clone());
+    let merged = services.clone();
+    drop(services);
+
+    if let Err(e) = consul::persist_discovered_services(&app_handle, &merged).await {
+        warn!("Failed to persist discovered services: {}", e);
+    }
+
+    info!("🎯 Socket scan complete: found {} gRPC service(s)", discovered.len());
+
+    Ok(GrpcResponse {
+        success: true,
+        message: format!(
+            "Discovered {} gRPC service(s) from {} listening socket(s)",
+            discovered.len(),
+            candidates.len()
+        ),
+        data: Some(serde_json::json!({
+            "services": discovered,
+            "scan_results": scan_results,
+            "sockets_probed": candidates.len(),
+            "mode": match mode {
+                ScanMode::Netstat => "netstat",
+                ScanMode::PortScan => "port_scan",
+            },
+        })),
+    })
+}
+
+fn fallback_candidates() -> Vec<ListeningSocket> {
+    COMMON_GRPC_PORTS
+        .iter()
+        .map(|&port| ListeningSocket { port, pid: None, process_name: None })
+        .collect()
+}
+
+fn list_listening_sockets() -> anyhow::Result<Vec<ListeningSocket>> {
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let proto_flags = ProtocolFlags::TCP;
+
+    let sockets_info = get_sockets_info(af_flags, proto_flags)?;
+
+    let mut seen_ports = HashSet::new();
+    let mut result = Vec::new();
+
+    for info in sockets_info {
+        if let ProtocolSocketInfo::Tcp(tcp) = info.protocol_socket_info {
+            if tcp.state != TcpState::Listen {
+                continue;
+            }
+            if !seen_ports.insert(tcp.local_port) {
+                continue;
+            }
+
+            let pid = info.associated_pids.first().copied();
+            let process_name = pid.and_then(process_name_for_pid);
+            result.push(ListeningSocket { port: tcp.local_port, pid, process_name });
+        }
+    }
+
+    Ok(result)
+}
+
+// `netstat2` reports PIDs but not process names; resolve the name from
+// /proc on platforms that have it. Best-effort, matching the "where the
+// platform permits" scoping in the request.
+#[cfg(target_os = "linux")]
+fn process_name_for_pid(pid: u32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{}/comm", pid))
+        .ok()
+        .map(|s| s.trim().to_string())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_name_for_pid(_pid: u32) -> Option<String> {
+    None
+}
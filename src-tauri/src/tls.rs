@@ -0,0 +1,87 @@
+// Mutual TLS / custom CA support for outbound gRPC connections: PEM
+// material in, a `ClientTlsConfig` out for `connect_grpc` and
+// `is_grpc_service` to hand to tonic, with connect errors classified so
+// the frontend can tell a handshake/verification failure apart from a
+// plain connection refusal.
+
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+use tonic::transport::{Certificate, ClientTlsConfig, Identity};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct TlsConfig {
+    pub(crate) ca_cert_pem: Option<String>,
+    pub(crate) client_cert_pem: Option<String>,
+    pub(crate) client_key_pem: Option<String>,
+    // Overrides SNI/the name verified against the server's certificate,
+    // for servers reached via an address that doesn't match their cert.
+    pub(crate) server_name: Option<String>,
+}
+
+pub(crate) fn build_client_tls_config(tls: &TlsConfig) -> anyhow::Result<ClientTlsConfig> {
+    let mut config = ClientTlsConfig::new();
+
+    if let Some(ca) = &tls.ca_cert_pem {
+        config = config.ca_certificate(Certificate::from_pem(ca));
+    }
+
+    match (&tls.client_cert_pem, &tls.client_key_pem) {
+        (Some(cert), Some(key)) => {
+            config = config.identity(Identity::from_pem(cert, key));
+        }
+        (None, None) => {}
+        _ => anyhow::bail!("client_cert_pem and client_key_pem must both be set for mutual TLS"),
+    }
+
+    if let Some(server_name) = &tls.server_name {
+        config = config.domain_name(server_name.clone());
+    }
+
+    Ok(config)
+}
+
+// tonic's transport errors don't expose a stable "kind" we can match on, so
+// this falls back to inspecting the error chain's text. Good enough to tell
+// the two failure modes the request cares about apart.
+pub(crate) fn describe_connect_error(error: &tonic::transport::Error) -> String {
+    let chain = std::iter::successors(Some(error as &dyn std::error::Error), |e| e.source())
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join(": ");
+    let lower = chain.to_lowercase();
+
+    if lower.contains("certificate") || lower.contains("tls") || lower.contains("handshake") {
+        format!("TLS handshake/verification failed: {}", chain)
+    } else if lower.contains("connection refused") || lower.contains("os error 111") {
+        format!("Connection refused: {}", chain)
+    } else {
+        format!("Connection failed: {}", chain)
+    }
+}
+
+// Distinguishes pool entries by TLS identity, so a plaintext connection and
+// an mTLS connection to the same host:port don't collide on one channel.
+pub(crate) fn pool_key(host: &str, port: u16, tls: Option<&TlsConfig>) -> String {
+    let identity = match tls {
+        None => "plain".to_string(),
+        Some(tls) => {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            tls.ca_cert_pem.hash(&mut hasher);
+            tls.client_cert_pem.hash(&mut hasher);
+            tls.client_key_pem.hash(&mut hasher);
+            tls.server_name.hash(&mut hasher);
+            format!("tls-{:x}", hasher.finish())
+        }
+    };
+    format!("{}:{}|{}", host, port, identity)
+}
+
+// True if `key` is a `pool_key` for this `host:port`, regardless of TLS
+// identity. `:` doesn't close the port the way `|` closes the identity
+// suffix, so this checks for that `|` rather than a bare `starts_with`,
+// which would also match e.g. port 90 against a pool key for port 9090.
+pub(crate) fn pool_key_matches(key: &str, host: &str, port: u16) -> bool {
+    let prefix = format!("{}:{}", host, port);
+    key.strip_prefix(&prefix).is_some_and(|rest| rest.starts_with('|'))
+}